@@ -1,35 +1,59 @@
 use super::sqlite::SqliteMemory;
-use super::traits::{Memory, MemoryCategory, MemoryEntry};
+use super::traits::{Memory, MemoryCategory, MemoryEntry, UpdateMode};
 use async_trait::async_trait;
 use chrono::Local;
 use parking_lot::Mutex;
-use std::collections::HashSet;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::process::Command;
 use tokio::time::timeout;
 
+/// Monotonic time source for the failure cooldown, swappable in tests.
+trait Clock: Send + Sync {
+    fn now(&self) -> Instant;
+}
+
+struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
 pub struct LucidMemory {
     local: SqliteMemory,
     lucid_cmd: String,
     token_budget: usize,
+    sync_budget: usize,
     workspace_dir: PathBuf,
     recall_timeout: Duration,
     store_timeout: Duration,
     local_hit_threshold: usize,
     failure_cooldown: Duration,
+    dedup_threshold: f64,
     last_failure_at: Mutex<Option<Instant>>,
+    clock: Arc<dyn Clock>,
 }
 
 impl LucidMemory {
     const DEFAULT_LUCID_CMD: &'static str = "lucid";
     const DEFAULT_TOKEN_BUDGET: usize = 200;
+    // Upper bound on characters of content sent per `lucid store`; sqlite
+    // keeps the full text, lucid only needs enough for context recall.
+    const DEFAULT_SYNC_BUDGET_CHARS: usize = 4_000;
     // Lucid CLI cold start can exceed 120ms on slower machines, which causes
     // avoidable fallback to local-only memory and premature cooldown.
     const DEFAULT_RECALL_TIMEOUT_MS: u64 = 500;
     const DEFAULT_STORE_TIMEOUT_MS: u64 = 800;
     const DEFAULT_LOCAL_HIT_THRESHOLD: usize = 3;
     const DEFAULT_FAILURE_COOLDOWN_MS: u64 = 15_000;
+    // Token-set Jaccard similarity at or above which merged recall entries
+    // are treated as duplicates (1.0 = identical after normalization, 0 = off).
+    const DEFAULT_DEDUP_THRESHOLD: f64 = 0.9;
 
     pub fn new(workspace_dir: &Path, local: SqliteMemory) -> Self {
         let lucid_cmd = std::env::var("ZEROCLAW_LUCID_CMD")
@@ -41,6 +65,11 @@ impl LucidMemory {
             .filter(|v| *v > 0)
             .unwrap_or(Self::DEFAULT_TOKEN_BUDGET);
 
+        let sync_budget = Self::read_env_usize(
+            "ZEROCLAW_LUCID_SYNC_BUDGET",
+            Self::DEFAULT_SYNC_BUDGET_CHARS,
+            64,
+        );
         let recall_timeout = Self::read_env_duration_ms(
             "ZEROCLAW_LUCID_RECALL_TIMEOUT_MS",
             Self::DEFAULT_RECALL_TIMEOUT_MS,
@@ -61,17 +90,24 @@ impl LucidMemory {
             Self::DEFAULT_FAILURE_COOLDOWN_MS,
             100,
         );
+        let dedup_threshold = Self::read_env_ratio(
+            "ZEROCLAW_LUCID_DEDUP_THRESHOLD",
+            Self::DEFAULT_DEDUP_THRESHOLD,
+        );
 
         Self {
             local,
             lucid_cmd,
             token_budget,
+            sync_budget,
             workspace_dir: workspace_dir.to_path_buf(),
             recall_timeout,
             store_timeout,
             local_hit_threshold,
             failure_cooldown,
+            dedup_threshold,
             last_failure_at: Mutex::new(None),
+            clock: Arc::new(SystemClock),
         }
     }
 
@@ -82,24 +118,35 @@ impl LucidMemory {
         local: SqliteMemory,
         lucid_cmd: String,
         token_budget: usize,
+        sync_budget: usize,
         local_hit_threshold: usize,
         recall_timeout: Duration,
         store_timeout: Duration,
         failure_cooldown: Duration,
+        dedup_threshold: f64,
     ) -> Self {
         Self {
             local,
             lucid_cmd,
             token_budget,
+            sync_budget: sync_budget.max(1),
             workspace_dir: workspace_dir.to_path_buf(),
             recall_timeout,
             store_timeout,
             local_hit_threshold: local_hit_threshold.max(1),
             failure_cooldown,
+            dedup_threshold: dedup_threshold.clamp(0.0, 1.0),
             last_failure_at: Mutex::new(None),
+            clock: Arc::new(SystemClock),
         }
     }
 
+    #[cfg(test)]
+    fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     fn read_env_usize(name: &str, default: usize, min: usize) -> usize {
         std::env::var(name)
             .ok()
@@ -107,6 +154,14 @@ impl LucidMemory {
             .map_or(default, |v| v.max(min))
     }
 
+    fn read_env_ratio(name: &str, default: f64) -> f64 {
+        std::env::var(name)
+            .ok()
+            .and_then(|v| v.parse::<f64>().ok())
+            .filter(|v| v.is_finite())
+            .map_or(default, |v| v.clamp(0.0, 1.0))
+    }
+
     fn read_env_duration_ms(name: &str, default_ms: u64, min_ms: u64) -> Duration {
         let millis = std::env::var(name)
             .ok()
@@ -117,14 +172,15 @@ impl LucidMemory {
 
     fn in_failure_cooldown(&self) -> bool {
         let guard = self.last_failure_at.lock();
+        let now = self.clock.now();
         guard
             .as_ref()
-            .is_some_and(|last| last.elapsed() < self.failure_cooldown)
+            .is_some_and(|last| now.saturating_duration_since(*last) < self.failure_cooldown)
     }
 
     fn mark_failure_now(&self) {
         let mut guard = self.last_failure_at.lock();
-        *guard = Some(Instant::now());
+        *guard = Some(self.clock.now());
     }
 
     fn clear_failure(&self) {
@@ -155,40 +211,78 @@ impl LucidMemory {
         }
     }
 
+    /// Lowercased alphanumeric tokens, so punctuation and casing don't
+    /// distinguish otherwise identical memories.
+    fn normalized_tokens(content: &str) -> HashSet<String> {
+        content
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|token| !token.is_empty())
+            .map(str::to_lowercase)
+            .collect()
+    }
+
+    fn jaccard_similarity(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+        if a.is_empty() && b.is_empty() {
+            return 1.0;
+        }
+        let intersection = a.intersection(b).count();
+        let union = a.union(b).count();
+        intersection as f64 / union as f64
+    }
+
+    /// Merge local (primary) and lucid (secondary) hits. Every local entry is
+    /// kept; a lucid entry is dropped when it is a near duplicate of one
+    /// already kept. Scores from the two sources are not comparable, so kept
+    /// scores are left untouched. A threshold of 0 disables deduplication.
     fn merge_results(
         primary_results: Vec<MemoryEntry>,
         secondary_results: Vec<MemoryEntry>,
         limit: usize,
+        dedup_threshold: f64,
     ) -> Vec<MemoryEntry> {
         if limit == 0 {
             return Vec::new();
         }
 
-        let mut merged = Vec::new();
-        let mut seen = HashSet::new();
-
-        for entry in primary_results.into_iter().chain(secondary_results) {
-            let signature = format!(
-                "{}\u{0}{}",
-                entry.key.to_lowercase(),
-                entry.content.to_lowercase()
-            );
+        let mut merged: Vec<(MemoryEntry, HashSet<String>)> = primary_results
+            .into_iter()
+            .take(limit)
+            .map(|entry| {
+                let tokens = Self::normalized_tokens(&entry.content);
+                (entry, tokens)
+            })
+            .collect();
+
+        for entry in secondary_results {
+            let tokens = Self::normalized_tokens(&entry.content);
+            let duplicate = dedup_threshold > 0.0
+                && merged.iter().any(|(_, kept_tokens)| {
+                    Self::jaccard_similarity(kept_tokens, &tokens) >= dedup_threshold
+                });
+            if duplicate {
+                continue;
+            }
 
-            if seen.insert(signature) {
-                merged.push(entry);
-                if merged.len() >= limit {
-                    break;
-                }
+            if merged.len() >= limit {
+                break;
             }
+            merged.push((entry, tokens));
         }
 
-        merged
+        merged.into_iter().map(|(entry, _)| entry).collect()
     }
 
+    /// Parse `- [label] content` bullets from a `<lucid-context>` block.
+    ///
+    /// Non-bullet lines continue the previous bullet's content. A missing
+    /// closing tag is tolerated by reading to the end of the output, and
+    /// unparseable lines are counted and logged instead of silently dropped.
     fn parse_lucid_context(raw: &str) -> Vec<MemoryEntry> {
         let mut in_context_block = false;
-        let mut entries = Vec::new();
-        let now = Local::now().to_rfc3339();
+        let mut closed = false;
+        let mut bullets: Vec<(String, String)> = Vec::new();
+        let mut bullet_open = false;
+        let mut skipped = 0usize;
 
         for line in raw.lines().map(str::trim) {
             if line == "<lucid-context>" {
@@ -197,6 +291,7 @@ impl LucidMemory {
             }
 
             if line == "</lucid-context>" {
+                closed = true;
                 break;
             }
 
@@ -204,16 +299,37 @@ impl LucidMemory {
                 continue;
             }
 
-            let Some(rest) = line.strip_prefix("- [") else {
+            if let Some(rest) = line.strip_prefix("- [") {
+                if let Some((label, content_part)) = rest.split_once(']') {
+                    bullets.push((label.trim().to_string(), content_part.trim().to_string()));
+                    bullet_open = true;
+                } else {
+                    skipped += 1;
+                    bullet_open = false;
+                }
                 continue;
-            };
+            }
 
-            let Some((label, content_part)) = rest.split_once(']') else {
-                continue;
-            };
+            match bullets.last_mut() {
+                Some((_, content)) if bullet_open => {
+                    if !content.is_empty() {
+                        content.push('\n');
+                    }
+                    content.push_str(line);
+                }
+                _ => skipped += 1,
+            }
+        }
+
+        if in_context_block && !closed {
+            tracing::debug!("Lucid context missing closing tag; parsed to end of output");
+        }
 
-            let content = content_part.trim();
+        let now = Local::now().to_rfc3339();
+        let mut entries = Vec::new();
+        for (label, content) in bullets {
             if content.is_empty() {
+                skipped += 1;
                 continue;
             }
 
@@ -221,14 +337,22 @@ impl LucidMemory {
             entries.push(MemoryEntry {
                 id: format!("lucid:{rank}"),
                 key: format!("lucid_{rank}"),
-                content: content.to_string(),
-                category: Self::to_memory_category(label.trim()),
+                content,
+                category: Self::to_memory_category(&label),
                 timestamp: now.clone(),
                 session_id: None,
                 score: Some((1.0 - rank as f64 * 0.05).max(0.1)),
             });
         }
 
+        if skipped > 0 {
+            tracing::debug!(
+                skipped,
+                parsed = entries.len(),
+                "Skipped unparseable lucid context lines"
+            );
+        }
+
         entries
     }
 
@@ -273,17 +397,42 @@ impl LucidMemory {
         ]
     }
 
-    fn build_recall_args(&self, query: &str) -> Vec<String> {
-        vec![
+    fn build_recall_args(&self, query: &str, category: Option<&MemoryCategory>) -> Vec<String> {
+        let mut args = vec![
             "context".to_string(),
             query.to_string(),
             format!("--budget={}", self.token_budget),
             format!("--project={}", self.workspace_dir.display()),
-        ]
+        ];
+        if let Some(category) = category {
+            args.push(format!("--type={}", Self::to_lucid_type(category)));
+        }
+        args
+    }
+
+    /// Cap content sent to lucid at `sync_budget` characters (ellipsis included).
+    fn truncate_for_sync<'a>(&self, key: &str, content: &'a str) -> Cow<'a, str> {
+        let total_chars = content.chars().count();
+        if total_chars <= self.sync_budget {
+            return Cow::Borrowed(content);
+        }
+
+        tracing::debug!(
+            key,
+            original_chars = total_chars,
+            sync_budget = self.sync_budget,
+            "Truncating memory content synced to lucid"
+        );
+        let kept: String = content
+            .chars()
+            .take(self.sync_budget.saturating_sub(1))
+            .collect();
+        Cow::Owned(format!("{kept}…"))
     }
 
     async fn sync_to_lucid_async(&self, key: &str, content: &str, category: &MemoryCategory) {
-        let args = self.build_store_args(key, content, category);
+        let content = self.truncate_for_sync(key, content);
+        let args = self.build_store_args(key, &content, category);
         if let Err(error) = self.run_lucid_command(&args, self.store_timeout).await {
             tracing::debug!(
                 command = %self.lucid_cmd,
@@ -293,40 +442,35 @@ impl LucidMemory {
         }
     }
 
-    async fn recall_from_lucid(&self, query: &str) -> anyhow::Result<Vec<MemoryEntry>> {
-        let args = self.build_recall_args(query);
-        let output = self.run_lucid_command(&args, self.recall_timeout).await?;
-        Ok(Self::parse_lucid_context(&output))
-    }
-}
-
-#[async_trait]
-impl Memory for LucidMemory {
-    fn name(&self) -> &str {
-        "lucid"
-    }
-
-    async fn store(
+    async fn recall_from_lucid(
         &self,
-        key: &str,
-        content: &str,
-        category: MemoryCategory,
-        session_id: Option<&str>,
-    ) -> anyhow::Result<()> {
-        self.local
-            .store(key, content, category.clone(), session_id)
-            .await?;
-        self.sync_to_lucid_async(key, content, &category).await;
-        Ok(())
+        query: &str,
+        category: Option<&MemoryCategory>,
+    ) -> anyhow::Result<Vec<MemoryEntry>> {
+        let args = self.build_recall_args(query, category);
+        let output = self.run_lucid_command(&args, self.recall_timeout).await?;
+        let mut entries = Self::parse_lucid_context(&output);
+        // Lucid already filtered by `--type`; its labels map back lossily
+        // (e.g. daily -> context -> conversation), so keep the requested scope.
+        if let Some(category) = category {
+            for entry in &mut entries {
+                entry.category = category.clone();
+            }
+        }
+        Ok(entries)
     }
 
-    async fn recall(
+    async fn recall_scoped(
         &self,
         query: &str,
         limit: usize,
         session_id: Option<&str>,
+        category: Option<&MemoryCategory>,
     ) -> anyhow::Result<Vec<MemoryEntry>> {
-        let local_results = self.local.recall(query, limit, session_id).await?;
+        let local_results = match category {
+            Some(category) => self.local.recall_in(query, category, limit).await?,
+            None => self.local.recall(query, limit, session_id).await?,
+        };
         if limit == 0
             || local_results.len() >= limit
             || local_results.len() >= self.local_hit_threshold
@@ -334,14 +478,25 @@ impl Memory for LucidMemory {
             return Ok(local_results);
         }
 
+        // Every custom tag syncs to lucid as `learning`, so lucid cannot scope
+        // recall to one tag; its hits would carry entries from other tags.
+        if matches!(category, Some(MemoryCategory::Custom(_))) {
+            return Ok(local_results);
+        }
+
         if self.in_failure_cooldown() {
             return Ok(local_results);
         }
 
-        match self.recall_from_lucid(query).await {
+        match self.recall_from_lucid(query, category).await {
             Ok(lucid_results) if !lucid_results.is_empty() => {
                 self.clear_failure();
-                Ok(Self::merge_results(local_results, lucid_results, limit))
+                Ok(Self::merge_results(
+                    local_results,
+                    lucid_results,
+                    limit,
+                    self.dedup_threshold,
+                ))
             }
             Ok(_) => {
                 self.clear_failure();
@@ -358,6 +513,45 @@ impl Memory for LucidMemory {
             }
         }
     }
+}
+
+#[async_trait]
+impl Memory for LucidMemory {
+    fn name(&self) -> &str {
+        "lucid"
+    }
+
+    async fn store(
+        &self,
+        key: &str,
+        content: &str,
+        category: MemoryCategory,
+        session_id: Option<&str>,
+    ) -> anyhow::Result<()> {
+        self.local
+            .store(key, content, category.clone(), session_id)
+            .await?;
+        self.sync_to_lucid_async(key, content, &category).await;
+        Ok(())
+    }
+
+    async fn recall(
+        &self,
+        query: &str,
+        limit: usize,
+        session_id: Option<&str>,
+    ) -> anyhow::Result<Vec<MemoryEntry>> {
+        self.recall_scoped(query, limit, session_id, None).await
+    }
+
+    async fn recall_in(
+        &self,
+        query: &str,
+        category: &MemoryCategory,
+        limit: usize,
+    ) -> anyhow::Result<Vec<MemoryEntry>> {
+        self.recall_scoped(query, limit, None, Some(category)).await
+    }
 
     async fn get(&self, key: &str) -> anyhow::Result<Option<MemoryEntry>> {
         self.local.get(key).await
@@ -371,6 +565,17 @@ impl Memory for LucidMemory {
         self.local.list(category, session_id).await
     }
 
+    async fn update(&self, key: &str, content: &str, mode: UpdateMode) -> anyhow::Result<bool> {
+        if !self.local.update(key, content, mode).await? {
+            return Ok(false);
+        }
+        if let Some(entry) = self.local.get(key).await? {
+            self.sync_to_lucid_async(key, &entry.content, &entry.category)
+                .await;
+        }
+        Ok(true)
+    }
+
     async fn forget(&self, key: &str) -> anyhow::Result<bool> {
         self.local.forget(key).await
     }
@@ -379,6 +584,10 @@ impl Memory for LucidMemory {
         self.local.count().await
     }
 
+    async fn count_by_category(&self) -> anyhow::Result<HashMap<MemoryCategory, usize>> {
+        self.local.count_by_category().await
+    }
+
     async fn health_check(&self) -> bool {
         self.local.health_check().await
     }
@@ -496,13 +705,112 @@ exit 1
             sqlite,
             cmd,
             200,
+            LucidMemory::DEFAULT_SYNC_BUDGET_CHARS,
             3,
             Duration::from_millis(500),
             Duration::from_millis(400),
             Duration::from_secs(2),
+            LucidMemory::DEFAULT_DEDUP_THRESHOLD,
         )
     }
 
+    fn scored_entry(key: &str, content: &str, score: f64) -> MemoryEntry {
+        MemoryEntry {
+            id: key.to_string(),
+            key: key.to_string(),
+            content: content.to_string(),
+            category: MemoryCategory::Core,
+            timestamp: "2026-01-01T00:00:00+00:00".to_string(),
+            session_id: None,
+            score: Some(score),
+        }
+    }
+
+    #[test]
+    fn parse_lucid_context_reads_to_eof_without_closing_tag() {
+        let raw = "<lucid-context>\n\
+                   - [decision] Use token refresh middleware\n\
+                   - [context] Working in src/auth.rs\n";
+
+        let entries = LucidMemory::parse_lucid_context(raw);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].content, "Use token refresh middleware");
+        assert_eq!(entries[0].category, MemoryCategory::Core);
+        assert_eq!(entries[1].content, "Working in src/auth.rs");
+    }
+
+    #[test]
+    fn parse_lucid_context_joins_multi_line_bullets() {
+        let raw = "<lucid-context>\n\
+                   Auth context snapshot\n\
+                   - [decision] Rotate keys quarterly\n\
+                   \x20 and after every incident\n\
+                   - [broken label without bracket\n\
+                   dangling text after a malformed bullet\n\
+                   - [bug] Refresh race in session store\n\
+                   </lucid-context>\n\
+                   - [decision] outside the block";
+
+        let entries = LucidMemory::parse_lucid_context(raw);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            entries[0].content,
+            "Rotate keys quarterly\nand after every incident"
+        );
+        assert_eq!(entries[1].content, "Refresh race in session store");
+        assert_eq!(entries[1].category, MemoryCategory::Daily);
+    }
+
+    #[test]
+    fn merge_results_collapses_punctuation_and_case_variants() {
+        let local = vec![scored_entry("local", "use token refresh middleware", 0.4)];
+        let lucid = vec![
+            scored_entry("lucid_0", "Use token-refresh middleware!", 0.9),
+            scored_entry("lucid_1", "Auth lives in src/auth.rs", 0.8),
+        ];
+
+        let merged = LucidMemory::merge_results(local, lucid, 10, 0.9);
+
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].key, "local", "local entry survives the collision");
+        assert_eq!(merged[0].content, "use token refresh middleware");
+        assert_eq!(merged[0].score, Some(0.4), "local score is kept as-is");
+        assert_eq!(merged[1].key, "lucid_1");
+    }
+
+    #[test]
+    fn merge_results_threshold_controls_near_duplicates() {
+        let local = vec![scored_entry("a", "deploy with blue green strategy", 0.5)];
+        let lucid = vec![scored_entry("b", "deploy with blue green rollout", 0.5)];
+
+        // 4 shared tokens out of 6 distinct ones: Jaccard ~0.67.
+        let strict = LucidMemory::merge_results(local.clone(), lucid.clone(), 10, 0.9);
+        assert_eq!(strict.len(), 2);
+
+        let loose = LucidMemory::merge_results(local.clone(), lucid.clone(), 10, 0.6);
+        assert_eq!(loose.len(), 1);
+        assert_eq!(loose[0].key, "a", "ties keep the earlier entry");
+
+        let disabled = LucidMemory::merge_results(local, lucid, 10, 0.0);
+        assert_eq!(disabled.len(), 2, "threshold 0 disables dedup");
+    }
+
+    #[test]
+    fn merge_results_keeps_local_near_duplicates() {
+        let local = vec![
+            scored_entry("deploy_v1", "deploy with blue green", 0.7),
+            scored_entry("deploy_v2", "Deploy with blue-green!", 0.6),
+        ];
+        let lucid = vec![scored_entry("lucid_0", "deploy with blue green", 0.9)];
+
+        let merged = LucidMemory::merge_results(local, lucid, 10, 0.9);
+
+        let keys: Vec<&str> = merged.iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(keys, ["deploy_v1", "deploy_v2"]);
+    }
+
     #[tokio::test]
     async fn lucid_name() {
         let tmp = TempDir::new().unwrap();
@@ -587,10 +895,12 @@ exit 1
             sqlite,
             probe_cmd,
             200,
+            LucidMemory::DEFAULT_SYNC_BUDGET_CHARS,
             1,
             Duration::from_millis(500),
             Duration::from_millis(400),
             Duration::from_secs(2),
+            LucidMemory::DEFAULT_DEDUP_THRESHOLD,
         );
 
         memory
@@ -615,6 +925,195 @@ exit 1
         );
     }
 
+    fn write_args_logging_lucid_script(dir: &Path, marker_path: &Path) -> String {
+        let script_path = dir.join("args-lucid.sh");
+        let marker = marker_path.display().to_string();
+        let script = format!(
+            r#"#!/usr/bin/env bash
+set -euo pipefail
+
+printf '%s\n' "$*" >> "{marker}"
+
+if [[ "${{1:-}}" == "store" ]]; then
+  echo '{{"success":true,"id":"mem_store"}}'
+  exit 0
+fi
+
+if [[ "${{1:-}}" == "context" ]]; then
+  cat <<'EOF'
+<lucid-context>
+- [decision] Rotate deploy keys quarterly
+</lucid-context>
+EOF
+  exit 0
+fi
+
+echo "unsupported command" >&2
+exit 1
+"#
+        );
+
+        fs::write(&script_path, script).unwrap();
+        let mut perms = fs::metadata(&script_path).unwrap().permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&script_path, perms).unwrap();
+        script_path.display().to_string()
+    }
+
+    #[tokio::test]
+    async fn recall_in_scopes_local_and_lucid_by_category() {
+        let tmp = TempDir::new().unwrap();
+        let marker = tmp.path().join("context_args.log");
+        let cmd = write_args_logging_lucid_script(tmp.path(), &marker);
+        let memory = test_memory(tmp.path(), cmd);
+
+        memory
+            .store(
+                "core_deploy",
+                "deploy via blue green",
+                MemoryCategory::Core,
+                None,
+            )
+            .await
+            .unwrap();
+        memory
+            .store(
+                "daily_deploy",
+                "deploy ran at noon",
+                MemoryCategory::Daily,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let entries = memory
+            .recall_in("deploy", &MemoryCategory::Core, 5)
+            .await
+            .unwrap();
+
+        assert!(entries.iter().all(|e| e.category == MemoryCategory::Core));
+        assert!(entries.iter().any(|e| e.key == "core_deploy"));
+        assert!(!entries.iter().any(|e| e.key == "daily_deploy"));
+        assert!(entries
+            .iter()
+            .any(|e| e.content.contains("Rotate deploy keys")));
+
+        let args = tokio::fs::read_to_string(&marker).await.unwrap();
+        let context_args = args
+            .lines()
+            .find(|line| line.starts_with("context "))
+            .expect("lucid context call");
+        assert!(
+            context_args.contains("--type=decision"),
+            "got args: {context_args}"
+        );
+    }
+
+    #[tokio::test]
+    async fn recall_in_custom_tag_stays_local() {
+        let tmp = TempDir::new().unwrap();
+        let marker = tmp.path().join("lucid_args.log");
+        let cmd = write_args_logging_lucid_script(tmp.path(), &marker);
+        let memory = test_memory(tmp.path(), cmd);
+        let project = MemoryCategory::Custom("project".to_string());
+        let hobby = MemoryCategory::Custom("hobby".to_string());
+
+        memory
+            .store("project_deploy", "deploy the api", project.clone(), None)
+            .await
+            .unwrap();
+        memory
+            .store("hobby_deploy", "deploy the kite", hobby, None)
+            .await
+            .unwrap();
+
+        let entries = memory.recall_in("deploy", &project, 5).await.unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].key, "project_deploy");
+        assert_eq!(entries[0].category, project);
+
+        let args = tokio::fs::read_to_string(&marker).await.unwrap();
+        assert!(
+            !args.lines().any(|line| line.starts_with("context ")),
+            "custom-tag recall must not query lucid; got args: {args}"
+        );
+    }
+
+    #[tokio::test]
+    async fn store_truncates_content_to_sync_budget() {
+        let tmp = TempDir::new().unwrap();
+        let marker = tmp.path().join("lucid_args.log");
+        let cmd = write_args_logging_lucid_script(tmp.path(), &marker);
+
+        let sqlite = SqliteMemory::new(tmp.path()).unwrap();
+        let memory = LucidMemory::with_options(
+            tmp.path(),
+            sqlite,
+            cmd,
+            200,
+            16,
+            3,
+            Duration::from_millis(500),
+            Duration::from_millis(400),
+            Duration::from_secs(2),
+            LucidMemory::DEFAULT_DEDUP_THRESHOLD,
+        );
+
+        let long_content = "abcdefghijklmnopqrstuvwxyz0123456789";
+        memory
+            .store("note", long_content, MemoryCategory::Core, None)
+            .await
+            .unwrap();
+
+        let args = tokio::fs::read_to_string(&marker).await.unwrap();
+        let store_args = args
+            .lines()
+            .find(|line| line.starts_with("store "))
+            .expect("lucid store call");
+        assert!(
+            store_args.contains("note: abcdefghijklmno… --type=decision"),
+            "got args: {store_args}"
+        );
+        assert!(!store_args.contains(long_content));
+
+        let local = memory.get("note").await.unwrap().unwrap();
+        assert_eq!(local.content, long_content);
+    }
+
+    #[tokio::test]
+    async fn recall_passes_configured_token_budget() {
+        let tmp = TempDir::new().unwrap();
+        let marker = tmp.path().join("lucid_args.log");
+        let cmd = write_args_logging_lucid_script(tmp.path(), &marker);
+
+        let sqlite = SqliteMemory::new(tmp.path()).unwrap();
+        let memory = LucidMemory::with_options(
+            tmp.path(),
+            sqlite,
+            cmd,
+            321,
+            LucidMemory::DEFAULT_SYNC_BUDGET_CHARS,
+            3,
+            Duration::from_millis(500),
+            Duration::from_millis(400),
+            Duration::from_secs(2),
+            LucidMemory::DEFAULT_DEDUP_THRESHOLD,
+        );
+
+        memory.recall("deploy", 5, None).await.unwrap();
+
+        let args = tokio::fs::read_to_string(&marker).await.unwrap();
+        let context_args = args
+            .lines()
+            .find(|line| line.starts_with("context "))
+            .expect("lucid context call");
+        assert!(
+            context_args.contains("--budget=321"),
+            "got args: {context_args}"
+        );
+    }
+
     fn write_failing_lucid_script(dir: &Path, marker_path: &Path) -> String {
         let script_path = dir.join("failing-lucid.sh");
         let marker = marker_path.display().to_string();
@@ -645,6 +1144,66 @@ exit 1
         script_path.display().to_string()
     }
 
+    struct MockClock {
+        start: Instant,
+        offset: Mutex<Duration>,
+    }
+
+    impl MockClock {
+        fn new() -> Self {
+            Self {
+                start: Instant::now(),
+                offset: Mutex::new(Duration::ZERO),
+            }
+        }
+
+        fn advance(&self, by: Duration) {
+            *self.offset.lock() += by;
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> Instant {
+            self.start + *self.offset.lock()
+        }
+    }
+
+    #[tokio::test]
+    async fn failure_cooldown_expires_when_clock_advances() {
+        let tmp = TempDir::new().unwrap();
+        let marker = tmp.path().join("cooldown_context_calls.log");
+        let failing_cmd = write_failing_lucid_script(tmp.path(), &marker);
+        let clock = Arc::new(MockClock::new());
+
+        let sqlite = SqliteMemory::new(tmp.path()).unwrap();
+        let memory = LucidMemory::with_options(
+            tmp.path(),
+            sqlite,
+            failing_cmd,
+            200,
+            LucidMemory::DEFAULT_SYNC_BUDGET_CHARS,
+            99,
+            Duration::from_millis(500),
+            Duration::from_millis(400),
+            Duration::from_secs(60),
+            LucidMemory::DEFAULT_DEDUP_THRESHOLD,
+        )
+        .with_clock(clock.clone());
+
+        memory.recall("auth", 5, None).await.unwrap();
+        clock.advance(Duration::from_secs(59));
+        memory.recall("auth", 5, None).await.unwrap();
+
+        let calls = tokio::fs::read_to_string(&marker).await.unwrap_or_default();
+        assert_eq!(calls.lines().count(), 1, "still inside cooldown");
+
+        clock.advance(Duration::from_secs(2));
+        memory.recall("auth", 5, None).await.unwrap();
+
+        let calls = tokio::fs::read_to_string(&marker).await.unwrap_or_default();
+        assert_eq!(calls.lines().count(), 2, "lucid retried after cooldown");
+    }
+
     #[tokio::test]
     async fn failure_cooldown_avoids_repeated_lucid_calls() {
         let tmp = TempDir::new().unwrap();
@@ -657,10 +1216,12 @@ exit 1
             sqlite,
             failing_cmd,
             200,
+            LucidMemory::DEFAULT_SYNC_BUDGET_CHARS,
             99,
             Duration::from_millis(500),
             Duration::from_millis(400),
             Duration::from_secs(5),
+            LucidMemory::DEFAULT_DEDUP_THRESHOLD,
         );
 
         let first = memory.recall("auth", 5, None).await.unwrap();
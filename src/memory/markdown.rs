@@ -1,4 +1,4 @@
-use super::traits::{Memory, MemoryCategory, MemoryEntry};
+use super::traits::{Memory, MemoryCategory, MemoryEntry, UpdateMode};
 use async_trait::async_trait;
 use chrono::Local;
 use std::path::{Path, PathBuf};
@@ -216,6 +216,11 @@ impl Memory for MarkdownMemory {
         Ok(false)
     }
 
+    async fn update(&self, _key: &str, _content: &str, _mode: UpdateMode) -> anyhow::Result<bool> {
+        // Append-only like `forget`: existing entries are never rewritten
+        Ok(false)
+    }
+
     async fn count(&self) -> anyhow::Result<usize> {
         let all = self.read_all_entries().await?;
         Ok(all.len())
@@ -340,6 +345,23 @@ mod tests {
         assert!(!removed, "Markdown memory is append-only");
     }
 
+    #[tokio::test]
+    async fn markdown_update_is_noop() {
+        let (_tmp, mem) = temp_workspace();
+        mem.store("a", "permanent", MemoryCategory::Core, None)
+            .await
+            .unwrap();
+        let updated = mem
+            .update("a", "rewritten", UpdateMode::Append)
+            .await
+            .unwrap();
+        assert!(!updated, "Markdown memory is append-only");
+        assert_eq!(mem.count().await.unwrap(), 1);
+        let entry = mem.get("a").await.unwrap().unwrap();
+        assert!(entry.content.contains("permanent"));
+        assert!(!entry.content.contains("rewritten"));
+    }
+
     #[tokio::test]
     async fn markdown_empty_recall() {
         let (_tmp, mem) = temp_workspace();
@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
 use postgres::{Client, NoTls, Row};
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use uuid::Uuid;
@@ -305,6 +306,27 @@ impl Memory for PostgresMemory {
         .await?
     }
 
+    async fn count_by_category(&self) -> Result<HashMap<MemoryCategory, usize>> {
+        let client = self.client.clone();
+        let qualified_table = self.qualified_table.clone();
+
+        tokio::task::spawn_blocking(move || -> Result<HashMap<MemoryCategory, usize>> {
+            let mut client = client.lock();
+            let stmt =
+                format!("SELECT category, COUNT(*) FROM {qualified_table} GROUP BY category");
+            let mut counts = HashMap::new();
+            for row in client.query(&stmt, &[])? {
+                let category: String = row.get(0);
+                let count: i64 = row.get(1);
+                let count = usize::try_from(count)
+                    .context("PostgreSQL returned a negative memory count")?;
+                counts.insert(Self::parse_category(&category), count);
+            }
+            Ok(counts)
+        })
+        .await?
+    }
+
     async fn health_check(&self) -> bool {
         let client = self.client.clone();
         tokio::task::spawn_blocking(move || client.lock().simple_query("SELECT 1").is_ok())
@@ -1,11 +1,12 @@
 use super::embeddings::EmbeddingProvider;
-use super::traits::{Memory, MemoryCategory, MemoryEntry};
+use super::traits::{Memory, MemoryCategory, MemoryEntry, UpdateMode};
 use super::vector;
 use anyhow::Context;
 use async_trait::async_trait;
-use chrono::Local;
+use chrono::{DateTime, FixedOffset, Local, NaiveDateTime};
 use parking_lot::Mutex;
 use rusqlite::{params, Connection};
+use std::collections::HashMap;
 use std::fmt::Write as _;
 use std::path::{Path, PathBuf};
 use std::sync::mpsc;
@@ -17,6 +18,15 @@ use uuid::Uuid;
 /// Maximum allowed open timeout (seconds) to avoid unreasonable waits.
 const SQLITE_OPEN_TIMEOUT_CAP_SECS: u64 = 300;
 
+/// Keyword-fallback recall fetches `limit * N` candidates before ranking.
+const LIKE_CANDIDATE_MULTIPLIER: usize = 4;
+
+/// Age (days) at which a keyword-fallback hit's recency weight halves.
+const RECALL_RECENCY_HALF_LIFE_DAYS: f64 = 30.0;
+
+/// Compare-and-swap attempts `update` makes before giving up under contention.
+const UPDATE_MAX_ATTEMPTS: usize = 8;
+
 /// SQLite-backed persistent memory — the brain
 ///
 /// Full-stack search engine:
@@ -284,11 +294,75 @@ impl SqliteMemory {
         Ok(Some(embedding))
     }
 
-    /// FTS5 BM25 keyword search
+    /// Score a LIKE-fallback hit: the fraction of query tokens found in the
+    /// key or content. Kept in [0, 1] and independent of age so callers'
+    /// `min_relevance_score` filters treat old and new hits alike.
+    #[allow(clippy::cast_precision_loss)]
+    fn keyword_fallback_score(entry: &MemoryEntry, tokens: &[String]) -> f64 {
+        if tokens.is_empty() {
+            return 0.0;
+        }
+
+        let key = entry.key.to_lowercase();
+        let content = entry.content.to_lowercase();
+        let matched = tokens
+            .iter()
+            .filter(|t| key.contains(t.as_str()) || content.contains(t.as_str()))
+            .count();
+
+        matched as f64 / tokens.len() as f64
+    }
+
+    /// Exponential decay with a `RECALL_RECENCY_HALF_LIFE_DAYS` half-life.
+    /// Unparseable timestamps are treated as one half-life old.
+    fn recency_weight(timestamp: &str, now: DateTime<Local>) -> f64 {
+        let Some(ts) = Self::parse_timestamp(timestamp) else {
+            return 0.5;
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        let age_days =
+            now.signed_duration_since(ts).num_milliseconds().max(0) as f64 / 86_400_000.0;
+        0.5_f64.powf(age_days / RECALL_RECENCY_HALF_LIFE_DAYS)
+    }
+
+    /// Parse a stored timestamp. Rows are written as `Local::now().to_rfc3339()`,
+    /// but offset-less SQLite-style values (`YYYY-MM-DD HH:MM:SS`) are read as UTC.
+    fn parse_timestamp(timestamp: &str) -> Option<DateTime<FixedOffset>> {
+        let timestamp = timestamp.trim();
+        DateTime::parse_from_rfc3339(timestamp).ok().or_else(|| {
+            ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"]
+                .iter()
+                .find_map(|fmt| NaiveDateTime::parse_from_str(timestamp, fmt).ok())
+                .map(|naive| naive.and_utc().fixed_offset())
+        })
+    }
+
+    /// Order fallback hits by score weighted with recency, breaking ties by
+    /// newest timestamp first. Recency only affects order, never the score.
+    fn sort_by_score_then_recency(entries: &mut Vec<MemoryEntry>, now: DateTime<Local>) {
+        let mut ranked: Vec<(f64, Option<DateTime<FixedOffset>>, MemoryEntry)> = entries
+            .drain(..)
+            .map(|entry| {
+                let weighted =
+                    entry.score.unwrap_or(0.0) * Self::recency_weight(&entry.timestamp, now);
+                (weighted, Self::parse_timestamp(&entry.timestamp), entry)
+            })
+            .collect();
+        ranked.sort_by(|a, b| {
+            b.0.partial_cmp(&a.0)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| b.1.cmp(&a.1))
+        });
+        entries.extend(ranked.into_iter().map(|(_, _, entry)| entry));
+    }
+
+    /// FTS5 BM25 keyword search, optionally restricted to one category
     fn fts5_search(
         conn: &Connection,
         query: &str,
         limit: usize,
+        category: Option<&str>,
     ) -> anyhow::Result<Vec<(String, f32)>> {
         // Escape FTS5 special chars and build query
         let fts_query: String = query
@@ -305,6 +379,7 @@ impl SqliteMemory {
                    FROM memories_fts f
                    JOIN memories m ON m.rowid = f.rowid
                    WHERE memories_fts MATCH ?1
+                     AND (?3 IS NULL OR m.category = ?3)
                    ORDER BY score
                    LIMIT ?2";
 
@@ -312,7 +387,7 @@ impl SqliteMemory {
         #[allow(clippy::cast_possible_wrap)]
         let limit_i64 = limit as i64;
 
-        let rows = stmt.query_map(params![fts_query, limit_i64], |row| {
+        let rows = stmt.query_map(params![fts_query, limit_i64, category], |row| {
             let id: String = row.get(0)?;
             let score: f64 = row.get(1)?;
             // BM25 returns negative scores (lower = better), negate for ranking
@@ -376,111 +451,14 @@ impl SqliteMemory {
         Ok(scored)
     }
 
-    /// Safe reindex: rebuild FTS5 + embeddings with rollback on failure
-    #[allow(dead_code)]
-    pub async fn reindex(&self) -> anyhow::Result<usize> {
-        // Step 1: Rebuild FTS5
-        {
-            let conn = self.conn.clone();
-            tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
-                let conn = conn.lock();
-                conn.execute_batch("INSERT INTO memories_fts(memories_fts) VALUES('rebuild');")?;
-                Ok(())
-            })
-            .await??;
-        }
-
-        // Step 2: Re-embed all memories that lack embeddings
-        if self.embedder.dimensions() == 0 {
-            return Ok(0);
-        }
-
-        let conn = self.conn.clone();
-        let entries: Vec<(String, String)> = tokio::task::spawn_blocking(move || {
-            let conn = conn.lock();
-            let mut stmt =
-                conn.prepare("SELECT id, content FROM memories WHERE embedding IS NULL")?;
-            let rows = stmt.query_map([], |row| {
-                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
-            })?;
-            Ok::<_, anyhow::Error>(rows.filter_map(std::result::Result::ok).collect())
-        })
-        .await??;
-
-        let mut count = 0;
-        for (id, content) in &entries {
-            if let Ok(Some(emb)) = self.get_or_compute_embedding(content).await {
-                let bytes = vector::vec_to_bytes(&emb);
-                let conn = self.conn.clone();
-                let id = id.clone();
-                tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
-                    let conn = conn.lock();
-                    conn.execute(
-                        "UPDATE memories SET embedding = ?1 WHERE id = ?2",
-                        params![bytes, id],
-                    )?;
-                    Ok(())
-                })
-                .await??;
-                count += 1;
-            }
-        }
-
-        Ok(count)
-    }
-}
-
-#[async_trait]
-impl Memory for SqliteMemory {
-    fn name(&self) -> &str {
-        "sqlite"
-    }
-
-    async fn store(
-        &self,
-        key: &str,
-        content: &str,
-        category: MemoryCategory,
-        session_id: Option<&str>,
-    ) -> anyhow::Result<()> {
-        // Compute embedding (async, before blocking work)
-        let embedding_bytes = self
-            .get_or_compute_embedding(content)
-            .await?
-            .map(|emb| vector::vec_to_bytes(&emb));
-
-        let conn = self.conn.clone();
-        let key = key.to_string();
-        let content = content.to_string();
-        let sid = session_id.map(String::from);
-
-        tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
-            let conn = conn.lock();
-            let now = Local::now().to_rfc3339();
-            let cat = Self::category_to_str(&category);
-            let id = Uuid::new_v4().to_string();
-
-            conn.execute(
-                "INSERT INTO memories (id, key, content, category, embedding, created_at, updated_at, session_id)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
-                 ON CONFLICT(key) DO UPDATE SET
-                    content = excluded.content,
-                    category = excluded.category,
-                    embedding = excluded.embedding,
-                    updated_at = excluded.updated_at,
-                    session_id = excluded.session_id",
-                params![id, key, content, cat, embedding_bytes, now, now, sid],
-            )?;
-            Ok(())
-        })
-        .await?
-    }
-
-    async fn recall(
+    /// Hybrid recall shared by `recall` and `recall_in`, optionally
+    /// restricted to a session and/or a category.
+    async fn recall_scoped(
         &self,
         query: &str,
         limit: usize,
         session_id: Option<&str>,
+        category: Option<&MemoryCategory>,
     ) -> anyhow::Result<Vec<MemoryEntry>> {
         if query.trim().is_empty() {
             return Ok(Vec::new());
@@ -492,19 +470,23 @@ impl Memory for SqliteMemory {
         let conn = self.conn.clone();
         let query = query.to_string();
         let sid = session_id.map(String::from);
+        let cat = category.map(Self::category_to_str);
         let vector_weight = self.vector_weight;
         let keyword_weight = self.keyword_weight;
 
         tokio::task::spawn_blocking(move || -> anyhow::Result<Vec<MemoryEntry>> {
             let conn = conn.lock();
             let session_ref = sid.as_deref();
+            let category_ref = cat.as_deref();
 
             // FTS5 BM25 keyword search
-            let keyword_results = Self::fts5_search(&conn, &query, limit * 2).unwrap_or_default();
+            let keyword_results =
+                Self::fts5_search(&conn, &query, limit * 2, category_ref).unwrap_or_default();
 
             // Vector similarity search (if embeddings available)
             let vector_results = if let Some(ref qe) = query_embedding {
-                Self::vector_search(&conn, qe, limit * 2, None, session_ref).unwrap_or_default()
+                Self::vector_search(&conn, qe, limit * 2, category_ref, session_ref)
+                    .unwrap_or_default()
             } else {
                 Vec::new()
             };
@@ -592,11 +574,12 @@ impl Memory for SqliteMemory {
             // which helps prepared-statement cache efficiency.
             if results.is_empty() {
                 const MAX_LIKE_KEYWORDS: usize = 8;
-                let keywords: Vec<String> = query
+                let tokens: Vec<String> = query
                     .split_whitespace()
                     .take(MAX_LIKE_KEYWORDS)
-                    .map(|w| format!("%{w}%"))
+                    .map(str::to_lowercase)
                     .collect();
+                let keywords: Vec<String> = tokens.iter().map(|w| format!("%{w}%")).collect();
                 if !keywords.is_empty() {
                     let conditions: Vec<String> = keywords
                         .iter()
@@ -605,13 +588,19 @@ impl Memory for SqliteMemory {
                             format!("(content LIKE ?{} OR key LIKE ?{})", i * 2 + 1, i * 2 + 2)
                         })
                         .collect();
-                    let where_clause = conditions.join(" OR ");
+                    let mut where_clause = format!("({})", conditions.join(" OR "));
+                    let mut next_idx = keywords.len() * 2 + 1;
+                    if category_ref.is_some() {
+                        let _ = write!(where_clause, " AND category = ?{next_idx}");
+                        next_idx += 1;
+                    }
+                    // Pick candidates by created_at, the same timestamp the
+                    // recency weight and tie-break rank on.
                     let sql = format!(
                         "SELECT id, key, content, category, created_at, session_id FROM memories
                          WHERE {where_clause}
-                         ORDER BY updated_at DESC
-                         LIMIT ?{}",
-                        keywords.len() * 2 + 1
+                         ORDER BY julianday(created_at) DESC
+                         LIMIT ?{next_idx}"
                     );
                     let mut stmt = conn.prepare(&sql)?;
                     let mut param_values: Vec<Box<dyn rusqlite::types::ToSql>> = Vec::new();
@@ -619,8 +608,15 @@ impl Memory for SqliteMemory {
                         param_values.push(Box::new(kw.clone()));
                         param_values.push(Box::new(kw.clone()));
                     }
+                    if let Some(cat) = category_ref {
+                        param_values.push(Box::new(cat.to_string()));
+                    }
+                    // Over-fetch so token scoring can promote older but
+                    // better-matching rows above the newest partial matches.
                     #[allow(clippy::cast_possible_wrap)]
-                    param_values.push(Box::new(limit as i64));
+                    param_values.push(Box::new(
+                        limit.saturating_mul(LIKE_CANDIDATE_MULTIPLIER) as i64
+                    ));
                     let params_ref: Vec<&dyn rusqlite::types::ToSql> =
                         param_values.iter().map(AsRef::as_ref).collect();
                     let rows = stmt.query_map(params_ref.as_slice(), |row| {
@@ -631,18 +627,21 @@ impl Memory for SqliteMemory {
                             category: Self::str_to_category(&row.get::<_, String>(3)?),
                             timestamp: row.get(4)?,
                             session_id: row.get(5)?,
-                            score: Some(1.0),
+                            score: None,
                         })
                     })?;
+                    let now = Local::now();
                     for row in rows {
-                        let entry = row?;
+                        let mut entry = row?;
                         if let Some(sid) = session_ref {
                             if entry.session_id.as_deref() != Some(sid) {
                                 continue;
                             }
                         }
+                        entry.score = Some(Self::keyword_fallback_score(&entry, &tokens));
                         results.push(entry);
                     }
+                    Self::sort_by_score_then_recency(&mut results, now);
                 }
             }
 
@@ -652,6 +651,166 @@ impl Memory for SqliteMemory {
         .await?
     }
 
+    /// Safe reindex: rebuild FTS5 + embeddings with rollback on failure
+    #[allow(dead_code)]
+    pub async fn reindex(&self) -> anyhow::Result<usize> {
+        // Step 1: Rebuild FTS5
+        {
+            let conn = self.conn.clone();
+            tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
+                let conn = conn.lock();
+                conn.execute_batch("INSERT INTO memories_fts(memories_fts) VALUES('rebuild');")?;
+                Ok(())
+            })
+            .await??;
+        }
+
+        // Step 2: Re-embed all memories that lack embeddings
+        if self.embedder.dimensions() == 0 {
+            return Ok(0);
+        }
+
+        let conn = self.conn.clone();
+        let entries: Vec<(String, String)> = tokio::task::spawn_blocking(move || {
+            let conn = conn.lock();
+            let mut stmt =
+                conn.prepare("SELECT id, content FROM memories WHERE embedding IS NULL")?;
+            let rows = stmt.query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?;
+            Ok::<_, anyhow::Error>(rows.filter_map(std::result::Result::ok).collect())
+        })
+        .await??;
+
+        let mut count = 0;
+        for (id, content) in &entries {
+            if let Ok(Some(emb)) = self.get_or_compute_embedding(content).await {
+                let bytes = vector::vec_to_bytes(&emb);
+                let conn = self.conn.clone();
+                let id = id.clone();
+                tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
+                    let conn = conn.lock();
+                    conn.execute(
+                        "UPDATE memories SET embedding = ?1 WHERE id = ?2",
+                        params![bytes, id],
+                    )?;
+                    Ok(())
+                })
+                .await??;
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Most recent memories first, optionally only those created after `since`.
+    ///
+    /// Ordering uses `julianday(created_at)` rather than string order, so
+    /// entries written under different UTC offsets still sort correctly. Rows
+    /// with unparseable timestamps sort last and are dropped when `since` is set.
+    pub async fn recent(
+        &self,
+        limit: usize,
+        since: Option<DateTime<Local>>,
+    ) -> anyhow::Result<Vec<MemoryEntry>> {
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let conn = self.conn.clone();
+        let since = since.map(|cutoff| cutoff.to_rfc3339());
+
+        tokio::task::spawn_blocking(move || -> anyhow::Result<Vec<MemoryEntry>> {
+            let conn = conn.lock();
+            let mut stmt = conn.prepare(
+                "SELECT id, key, content, category, created_at, session_id FROM memories
+                 WHERE (?1 IS NULL OR julianday(created_at) > julianday(?1))
+                 ORDER BY julianday(created_at) IS NULL, julianday(created_at) DESC
+                 LIMIT ?2",
+            )?;
+            let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+            let rows = stmt.query_map(params![since, limit], |row| {
+                Ok(MemoryEntry {
+                    id: row.get(0)?,
+                    key: row.get(1)?,
+                    content: row.get(2)?,
+                    category: Self::str_to_category(&row.get::<_, String>(3)?),
+                    timestamp: row.get(4)?,
+                    session_id: row.get(5)?,
+                    score: None,
+                })
+            })?;
+            Ok(rows.collect::<rusqlite::Result<_>>()?)
+        })
+        .await?
+    }
+}
+
+#[async_trait]
+impl Memory for SqliteMemory {
+    fn name(&self) -> &str {
+        "sqlite"
+    }
+
+    async fn store(
+        &self,
+        key: &str,
+        content: &str,
+        category: MemoryCategory,
+        session_id: Option<&str>,
+    ) -> anyhow::Result<()> {
+        // Compute embedding (async, before blocking work)
+        let embedding_bytes = self
+            .get_or_compute_embedding(content)
+            .await?
+            .map(|emb| vector::vec_to_bytes(&emb));
+
+        let conn = self.conn.clone();
+        let key = key.to_string();
+        let content = content.to_string();
+        let sid = session_id.map(String::from);
+
+        tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
+            let conn = conn.lock();
+            let now = Local::now().to_rfc3339();
+            let cat = Self::category_to_str(&category);
+            let id = Uuid::new_v4().to_string();
+
+            conn.execute(
+                "INSERT INTO memories (id, key, content, category, embedding, created_at, updated_at, session_id)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                 ON CONFLICT(key) DO UPDATE SET
+                    content = excluded.content,
+                    category = excluded.category,
+                    embedding = excluded.embedding,
+                    updated_at = excluded.updated_at,
+                    session_id = excluded.session_id",
+                params![id, key, content, cat, embedding_bytes, now, now, sid],
+            )?;
+            Ok(())
+        })
+        .await?
+    }
+
+    async fn recall(
+        &self,
+        query: &str,
+        limit: usize,
+        session_id: Option<&str>,
+    ) -> anyhow::Result<Vec<MemoryEntry>> {
+        self.recall_scoped(query, limit, session_id, None).await
+    }
+
+    async fn recall_in(
+        &self,
+        query: &str,
+        category: &MemoryCategory,
+        limit: usize,
+    ) -> anyhow::Result<Vec<MemoryEntry>> {
+        self.recall_scoped(query, limit, None, Some(category)).await
+    }
+
     async fn get(&self, key: &str) -> anyhow::Result<Option<MemoryEntry>> {
         let conn = self.conn.clone();
         let key = key.to_string();
@@ -748,6 +907,49 @@ impl Memory for SqliteMemory {
         .await?
     }
 
+    async fn update(&self, key: &str, content: &str, mode: UpdateMode) -> anyhow::Result<bool> {
+        // Embeddings are computed outside the connection lock, so the write is
+        // a compare-and-swap on the content that was read: a concurrent update
+        // in between makes it miss, and we re-read and retry instead of losing it.
+        for _ in 0..UPDATE_MAX_ATTEMPTS {
+            let Some(existing) = self.get(key).await? else {
+                return Ok(false);
+            };
+
+            let new_content = mode.apply(&existing.content, content);
+
+            // Compute embedding (async, before blocking work)
+            let embedding_bytes = self
+                .get_or_compute_embedding(&new_content)
+                .await?
+                .map(|emb| vector::vec_to_bytes(&emb));
+
+            let conn = self.conn.clone();
+            let key = key.to_string();
+
+            // Touch only content, embedding, and updated_at so created_at survives.
+            let swapped = tokio::task::spawn_blocking(move || -> anyhow::Result<bool> {
+                let conn = conn.lock();
+                let now = Local::now().to_rfc3339();
+                let affected = conn.execute(
+                    "UPDATE memories SET content = ?1, embedding = ?2, updated_at = ?3
+                     WHERE key = ?4 AND content = ?5",
+                    params![new_content, embedding_bytes, now, key, existing.content],
+                )?;
+                Ok(affected > 0)
+            })
+            .await??;
+
+            if swapped {
+                return Ok(true);
+            }
+        }
+
+        anyhow::bail!(
+            "Memory '{key}' kept changing during update; gave up after {UPDATE_MAX_ATTEMPTS} attempts"
+        )
+    }
+
     async fn forget(&self, key: &str) -> anyhow::Result<bool> {
         let conn = self.conn.clone();
         let key = key.to_string();
@@ -773,6 +975,28 @@ impl Memory for SqliteMemory {
         .await?
     }
 
+    async fn count_by_category(&self) -> anyhow::Result<HashMap<MemoryCategory, usize>> {
+        let conn = self.conn.clone();
+
+        tokio::task::spawn_blocking(move || -> anyhow::Result<HashMap<MemoryCategory, usize>> {
+            let conn = conn.lock();
+            let mut stmt =
+                conn.prepare("SELECT category, COUNT(*) FROM memories GROUP BY category")?;
+            let rows = stmt.query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+            })?;
+
+            let mut counts = HashMap::new();
+            for row in rows {
+                let (cat, count) = row?;
+                #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+                counts.insert(Self::str_to_category(&cat), count as usize);
+            }
+            Ok(counts)
+        })
+        .await?
+    }
+
     async fn health_check(&self) -> bool {
         let conn = self.conn.clone();
         tokio::task::spawn_blocking(move || conn.lock().execute_batch("SELECT 1").is_ok())
@@ -834,6 +1058,96 @@ mod tests {
         assert_eq!(mem.count().await.unwrap(), 1);
     }
 
+    fn created_and_updated_at(mem: &SqliteMemory, key: &str) -> (String, String) {
+        mem.conn
+            .lock()
+            .query_row(
+                "SELECT created_at, updated_at FROM memories WHERE key = ?1",
+                params![key],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn sqlite_update_append_preserves_created_at() {
+        let (_tmp, mem) = temp_sqlite();
+        mem.store("notes", "first line", MemoryCategory::Daily, Some("s1"))
+            .await
+            .unwrap();
+        mem.conn
+            .lock()
+            .execute(
+                "UPDATE memories SET created_at = ?1, updated_at = ?1 WHERE key = 'notes'",
+                params!["2020-01-01T00:00:00+00:00"],
+            )
+            .unwrap();
+
+        let updated = mem
+            .update("notes", "second line", UpdateMode::Append)
+            .await
+            .unwrap();
+        assert!(updated);
+
+        let entry = mem.get("notes").await.unwrap().unwrap();
+        assert_eq!(entry.content, "first line\nsecond line");
+        assert_eq!(entry.category, MemoryCategory::Daily);
+        assert_eq!(entry.session_id.as_deref(), Some("s1"));
+
+        let (created_at, updated_at) = created_and_updated_at(&mem, "notes");
+        assert_eq!(created_at, "2020-01-01T00:00:00+00:00");
+        assert!(
+            DateTime::parse_from_rfc3339(&updated_at).unwrap()
+                > DateTime::parse_from_rfc3339(&created_at).unwrap()
+        );
+        assert_eq!(mem.count().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn sqlite_update_concurrent_appends_both_survive() {
+        let (_tmp, mem) = temp_sqlite();
+        mem.store("log", "start", MemoryCategory::Daily, None)
+            .await
+            .unwrap();
+
+        let (first, second) = tokio::join!(
+            mem.update("log", "from a", UpdateMode::Append),
+            mem.update("log", "from b", UpdateMode::Append),
+        );
+        assert!(first.unwrap());
+        assert!(second.unwrap());
+
+        let content = mem.get("log").await.unwrap().unwrap().content;
+        let mut lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.remove(0), "start");
+        lines.sort_unstable();
+        assert_eq!(lines, ["from a", "from b"]);
+    }
+
+    #[tokio::test]
+    async fn sqlite_update_replace_and_missing_key() {
+        let (_tmp, mem) = temp_sqlite();
+        mem.store("pref", "likes Rust", MemoryCategory::Core, None)
+            .await
+            .unwrap();
+
+        assert!(mem
+            .update("pref", "loves Rust", UpdateMode::Replace)
+            .await
+            .unwrap());
+        assert_eq!(
+            mem.get("pref").await.unwrap().unwrap().content,
+            "loves Rust"
+        );
+        assert_eq!(mem.recall("loves", 10, None).await.unwrap().len(), 1);
+
+        assert!(!mem
+            .update("missing", "anything", UpdateMode::Append)
+            .await
+            .unwrap());
+        assert!(mem.get("missing").await.unwrap().is_none());
+    }
+
     #[tokio::test]
     async fn sqlite_recall_keyword() {
         let (_tmp, mem) = temp_sqlite();
@@ -948,6 +1262,63 @@ mod tests {
         assert_eq!(mem.count().await.unwrap(), 0);
     }
 
+    #[tokio::test]
+    async fn sqlite_count_by_category() {
+        let (_tmp, mem) = temp_sqlite();
+        mem.store("c1", "core one", MemoryCategory::Core, None)
+            .await
+            .unwrap();
+        mem.store("c2", "core two", MemoryCategory::Core, None)
+            .await
+            .unwrap();
+        mem.store("d1", "daily", MemoryCategory::Daily, None)
+            .await
+            .unwrap();
+        mem.store("v1", "chat", MemoryCategory::Conversation, None)
+            .await
+            .unwrap();
+        mem.store(
+            "p1",
+            "project",
+            MemoryCategory::Custom("project".into()),
+            None,
+        )
+        .await
+        .unwrap();
+        mem.store(
+            "p2",
+            "project",
+            MemoryCategory::Custom("project".into()),
+            None,
+        )
+        .await
+        .unwrap();
+        mem.store(
+            "r1",
+            "research",
+            MemoryCategory::Custom("research".into()),
+            None,
+        )
+        .await
+        .unwrap();
+
+        let counts = mem.count_by_category().await.unwrap();
+
+        assert_eq!(counts.len(), 5);
+        assert_eq!(counts[&MemoryCategory::Core], 2);
+        assert_eq!(counts[&MemoryCategory::Daily], 1);
+        assert_eq!(counts[&MemoryCategory::Conversation], 1);
+        assert_eq!(counts[&MemoryCategory::Custom("project".into())], 2);
+        assert_eq!(counts[&MemoryCategory::Custom("research".into())], 1);
+        assert_eq!(counts.values().sum::<usize>(), mem.count().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn sqlite_count_by_category_empty() {
+        let (_tmp, mem) = temp_sqlite();
+        assert!(mem.count_by_category().await.unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn sqlite_get_nonexistent() {
         let (_tmp, mem) = temp_sqlite();
@@ -1323,6 +1694,226 @@ mod tests {
         }
     }
 
+    // ── Keyword fallback ranking ─────────────────────────────────
+
+    fn set_created_at(mem: &SqliteMemory, key: &str, timestamp: &str) {
+        mem.conn
+            .lock()
+            .execute(
+                "UPDATE memories SET created_at = ?1 WHERE key = ?2",
+                params![timestamp, key],
+            )
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn keyword_fallback_ranks_more_matched_tokens_first() {
+        let (_tmp, mem) = temp_sqlite();
+        // Partial-word tokens miss FTS5 and exercise the LIKE fallback.
+        mem.store(
+            "both",
+            "rustaceans write fastidious code",
+            MemoryCategory::Core,
+            None,
+        )
+        .await
+        .unwrap();
+        mem.store("one", "rustaceans only", MemoryCategory::Core, None)
+            .await
+            .unwrap();
+        // The single-token match is newer, so recency alone would rank it first.
+        set_created_at(&mem, "both", "2020-01-01T00:00:00+00:00");
+        set_created_at(&mem, "one", "2020-01-10T00:00:00+00:00");
+
+        let results = mem.recall("rust fast", 10, None).await.unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].key, "both");
+        assert!(results[0].score.unwrap() > results[1].score.unwrap());
+    }
+
+    #[tokio::test]
+    async fn keyword_fallback_prefers_newer_entry_on_equal_matches() {
+        let (_tmp, mem) = temp_sqlite();
+        mem.store("older", "rustacean notes", MemoryCategory::Core, None)
+            .await
+            .unwrap();
+        mem.store("newer", "rustacean notes", MemoryCategory::Core, None)
+            .await
+            .unwrap();
+        set_created_at(&mem, "older", "2020-01-01T00:00:00+00:00");
+        set_created_at(&mem, "newer", "2020-02-01T00:00:00+02:00");
+
+        let results = mem.recall("rust", 10, None).await.unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].key, "newer");
+        assert_eq!(results[1].key, "older");
+        assert_eq!(results[0].score, results[1].score);
+    }
+
+    #[tokio::test]
+    async fn keyword_fallback_old_full_match_clears_default_relevance_threshold() {
+        // Default `memory.min_relevance_score` applied by the agent and channels.
+        const DEFAULT_MIN_RELEVANCE_SCORE: f64 = 0.4;
+
+        let (_tmp, mem) = temp_sqlite();
+        mem.store(
+            "ancient",
+            "rustaceans prefer fastidious reviews",
+            MemoryCategory::Core,
+            None,
+        )
+        .await
+        .unwrap();
+        set_created_at(&mem, "ancient", "2020-01-01T00:00:00+00:00");
+
+        let results = mem.recall("rust fast", 5, None).await.unwrap();
+        assert_eq!(results.len(), 1);
+        let score = results[0].score.unwrap();
+        assert!((score - 1.0).abs() < f64::EPSILON);
+        assert!(score >= DEFAULT_MIN_RELEVANCE_SCORE);
+    }
+
+    #[test]
+    fn recency_weight_decays_and_tolerates_bad_timestamps() {
+        let now = DateTime::parse_from_rfc3339("2026-03-01T00:00:00+00:00")
+            .unwrap()
+            .with_timezone(&Local);
+        let fresh = SqliteMemory::recency_weight("2026-03-01T00:00:00+00:00", now);
+        let month_old = SqliteMemory::recency_weight("2026-01-30T00:00:00+00:00", now);
+
+        assert!((fresh - 1.0).abs() < 1e-9);
+        assert!((month_old - 0.5).abs() < 1e-9);
+        assert!((SqliteMemory::recency_weight("not a timestamp", now) - 0.5).abs() < 1e-9);
+    }
+
+    // ── Recent history ───────────────────────────────────────────
+
+    #[tokio::test]
+    async fn recent_orders_by_parsed_timestamp_descending() {
+        let (_tmp, mem) = temp_sqlite();
+        for key in ["oldest", "middle", "newest", "garbled"] {
+            mem.store(key, "note", MemoryCategory::Conversation, None)
+                .await
+                .unwrap();
+        }
+        set_created_at(&mem, "oldest", "2020-01-01T00:00:00+00:00");
+        // 2020-01-02T09:00Z — string order alone would put this after "newest".
+        set_created_at(&mem, "middle", "2020-01-02T12:00:00+03:00");
+        set_created_at(&mem, "newest", "2020-01-02 10:00:00");
+        set_created_at(&mem, "garbled", "yesterday-ish");
+
+        let keys: Vec<String> = mem
+            .recent(10, None)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| e.key)
+            .collect();
+        assert_eq!(keys, ["newest", "middle", "oldest", "garbled"]);
+
+        let top = mem.recent(2, None).await.unwrap();
+        assert_eq!(top.len(), 2);
+        assert_eq!(top[0].key, "newest");
+        assert!(mem.recent(0, None).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn recent_filters_by_since_cutoff() {
+        let (_tmp, mem) = temp_sqlite();
+        for key in ["before", "after", "garbled"] {
+            mem.store(key, "note", MemoryCategory::Daily, None)
+                .await
+                .unwrap();
+        }
+        set_created_at(&mem, "before", "2020-03-01T00:00:00+00:00");
+        set_created_at(&mem, "after", "2020-03-10T00:00:00+00:00");
+        set_created_at(&mem, "garbled", "not a timestamp");
+
+        let cutoff = DateTime::parse_from_rfc3339("2020-03-05T00:00:00+00:00")
+            .unwrap()
+            .with_timezone(&Local);
+        let results = mem.recent(10, Some(cutoff)).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].key, "after");
+    }
+
+    // ── Category-scoped recall ───────────────────────────────────
+
+    #[tokio::test]
+    async fn recall_in_excludes_other_categories() {
+        let (_tmp, mem) = temp_sqlite();
+        mem.store(
+            "core_fact",
+            "deploy uses blue green",
+            MemoryCategory::Core,
+            None,
+        )
+        .await
+        .unwrap();
+        mem.store(
+            "daily_log",
+            "deploy ran at noon",
+            MemoryCategory::Daily,
+            None,
+        )
+        .await
+        .unwrap();
+        mem.store(
+            "project_note",
+            "deploy checklist lives in docs",
+            MemoryCategory::Custom("project".into()),
+            None,
+        )
+        .await
+        .unwrap();
+
+        let core = mem
+            .recall_in("deploy", &MemoryCategory::Core, 10)
+            .await
+            .unwrap();
+        assert_eq!(core.len(), 1);
+        assert_eq!(core[0].key, "core_fact");
+
+        let custom = mem
+            .recall_in("deploy", &MemoryCategory::Custom("project".into()), 10)
+            .await
+            .unwrap();
+        assert_eq!(custom.len(), 1);
+        assert_eq!(custom[0].key, "project_note");
+
+        let all = mem.recall("deploy", 10, None).await.unwrap();
+        assert_eq!(all.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn recall_in_applies_category_to_keyword_fallback() {
+        let (_tmp, mem) = temp_sqlite();
+        // Partial-word query skips FTS5 and hits the LIKE fallback.
+        mem.store(
+            "core_fact",
+            "deployment strategy",
+            MemoryCategory::Core,
+            None,
+        )
+        .await
+        .unwrap();
+        mem.store(
+            "daily_log",
+            "deployment finished",
+            MemoryCategory::Daily,
+            None,
+        )
+        .await
+        .unwrap();
+
+        let core = mem
+            .recall_in("deploy", &MemoryCategory::Core, 10)
+            .await
+            .unwrap();
+        assert_eq!(core.len(), 1);
+        assert_eq!(core[0].category, MemoryCategory::Core);
+    }
+
     // ── Edge cases: FTS5 special characters ──────────────────────
 
     #[tokio::test]
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// A single memory entry
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,7 +15,7 @@ pub struct MemoryEntry {
 }
 
 /// Memory categories for organization
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum MemoryCategory {
     /// Long-term facts, preferences, decisions
@@ -38,6 +39,26 @@ impl std::fmt::Display for MemoryCategory {
     }
 }
 
+/// How `Memory::update` combines new content with an existing entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateMode {
+    /// Overwrite the existing content
+    Replace,
+    /// Append to the existing content on a new line
+    Append,
+}
+
+impl UpdateMode {
+    /// Combine `existing` content with `content` according to this mode
+    pub fn apply(self, existing: &str, content: &str) -> String {
+        match self {
+            Self::Replace => content.to_string(),
+            Self::Append if existing.is_empty() => content.to_string(),
+            Self::Append => format!("{existing}\n{content}"),
+        }
+    }
+}
+
 /// Core memory trait — implement for any persistence backend
 #[async_trait]
 pub trait Memory: Send + Sync {
@@ -61,6 +82,22 @@ pub trait Memory: Send + Sync {
         session_id: Option<&str>,
     ) -> anyhow::Result<Vec<MemoryEntry>>;
 
+    /// Recall memories matching a query, restricted to a single category.
+    ///
+    /// The default filters over-fetched `recall` results; backends that can
+    /// filter at query time should override it.
+    async fn recall_in(
+        &self,
+        query: &str,
+        category: &MemoryCategory,
+        limit: usize,
+    ) -> anyhow::Result<Vec<MemoryEntry>> {
+        let mut entries = self.recall(query, limit.saturating_mul(4), None).await?;
+        entries.retain(|entry| &entry.category == category);
+        entries.truncate(limit);
+        Ok(entries)
+    }
+
     /// Get a specific memory by key
     async fn get(&self, key: &str) -> anyhow::Result<Option<MemoryEntry>>;
 
@@ -71,12 +108,44 @@ pub trait Memory: Send + Sync {
         session_id: Option<&str>,
     ) -> anyhow::Result<Vec<MemoryEntry>>;
 
+    /// Update an existing memory's content in place, keeping its category,
+    /// session, and creation time. Returns `false` if the key does not exist.
+    async fn update(&self, key: &str, content: &str, mode: UpdateMode) -> anyhow::Result<bool> {
+        let Some(existing) = self.get(key).await? else {
+            return Ok(false);
+        };
+
+        let new_content = mode.apply(&existing.content, content);
+
+        self.store(
+            key,
+            &new_content,
+            existing.category,
+            existing.session_id.as_deref(),
+        )
+        .await?;
+        Ok(true)
+    }
+
     /// Remove a memory by key
     async fn forget(&self, key: &str) -> anyhow::Result<bool>;
 
     /// Count total memories
     async fn count(&self) -> anyhow::Result<usize>;
 
+    /// Count memories per category; each custom category gets its own bucket.
+    ///
+    /// The default tallies `list(None, None)`, so it is only correct for
+    /// backends whose `list` is uncapped; backends that cap `list` or can
+    /// aggregate at query time should override it.
+    async fn count_by_category(&self) -> anyhow::Result<HashMap<MemoryCategory, usize>> {
+        let mut counts = HashMap::new();
+        for entry in self.list(None, None).await? {
+            *counts.entry(entry.category).or_insert(0) += 1;
+        }
+        Ok(counts)
+    }
+
     /// Health check
     async fn health_check(&self) -> bool;
 }
@@ -107,6 +176,13 @@ mod tests {
         assert_eq!(conversation, "\"conversation\"");
     }
 
+    #[test]
+    fn update_mode_apply_replaces_or_appends() {
+        assert_eq!(UpdateMode::Replace.apply("old", "new"), "new");
+        assert_eq!(UpdateMode::Append.apply("old", "new"), "old\nnew");
+        assert_eq!(UpdateMode::Append.apply("", "new"), "new");
+    }
+
     #[test]
     fn memory_entry_roundtrip_preserves_optional_fields() {
         let entry = MemoryEntry {
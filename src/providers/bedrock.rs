@@ -97,21 +97,25 @@ fn derive_signing_key(secret: &str, date: &str, region: &str, service: &str) ->
     hmac_sha256(&k_service, b"aws4_request")
 }
 
-/// Build the SigV4 `Authorization` header value.
+/// Join header names into the SigV4 `SignedHeaders` list.
+fn signed_headers(headers: &[(String, String)]) -> String {
+    headers
+        .iter()
+        .map(|(k, _)| k.as_str())
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+/// Build the SigV4 canonical request.
 ///
 /// `headers` must be sorted by lowercase header name.
-fn build_authorization_header(
-    credentials: &AwsCredentials,
+fn canonical_request(
     method: &str,
     canonical_uri: &str,
     query_string: &str,
     headers: &[(String, String)],
     payload: &[u8],
-    timestamp: &chrono::DateTime<chrono::Utc>,
 ) -> String {
-    let date_stamp = timestamp.format("%Y%m%d").to_string();
-    let amz_date = timestamp.format("%Y%m%dT%H%M%SZ").to_string();
-
     let mut canonical_headers = String::new();
     for (k, v) in headers {
         canonical_headers.push_str(k);
@@ -120,40 +124,56 @@ fn build_authorization_header(
         canonical_headers.push('\n');
     }
 
-    let signed_headers: String = headers
-        .iter()
-        .map(|(k, _)| k.as_str())
-        .collect::<Vec<_>>()
-        .join(";");
-
+    let signed_headers = signed_headers(headers);
     let payload_hash = sha256_hex(payload);
 
-    let canonical_request = format!(
+    format!(
         "{method}\n{canonical_uri}\n{query_string}\n{canonical_headers}\n{signed_headers}\n{payload_hash}"
-    );
-
-    let credential_scope = format!(
-        "{date_stamp}/{}/{SIGNING_SERVICE}/aws4_request",
-        credentials.region
-    );
+    )
+}
 
-    let string_to_sign = format!(
+/// Build the SigV4 string to sign for a canonical request.
+fn string_to_sign(amz_date: &str, credential_scope: &str, canonical_request: &str) -> String {
+    format!(
         "AWS4-HMAC-SHA256\n{amz_date}\n{credential_scope}\n{}",
         sha256_hex(canonical_request.as_bytes())
-    );
+    )
+}
+
+/// Build the SigV4 `Authorization` header value for `service`.
+///
+/// `headers` must be sorted by lowercase header name.
+fn build_authorization_header(
+    credentials: &AwsCredentials,
+    service: &str,
+    method: &str,
+    canonical_uri: &str,
+    query_string: &str,
+    headers: &[(String, String)],
+    payload: &[u8],
+    timestamp: &chrono::DateTime<chrono::Utc>,
+) -> String {
+    let date_stamp = timestamp.format("%Y%m%d").to_string();
+    let amz_date = timestamp.format("%Y%m%dT%H%M%SZ").to_string();
+
+    let canonical_request =
+        canonical_request(method, canonical_uri, query_string, headers, payload);
+    let credential_scope = format!("{date_stamp}/{}/{service}/aws4_request", credentials.region);
+    let string_to_sign = string_to_sign(&amz_date, &credential_scope, &canonical_request);
 
     let signing_key = derive_signing_key(
         &credentials.secret_access_key,
         &date_stamp,
         &credentials.region,
-        SIGNING_SERVICE,
+        service,
     );
 
     let signature = hex::encode(hmac_sha256(&signing_key, string_to_sign.as_bytes()));
 
     format!(
-        "AWS4-HMAC-SHA256 Credential={}/{credential_scope}, SignedHeaders={signed_headers}, Signature={signature}",
-        credentials.access_key_id
+        "AWS4-HMAC-SHA256 Credential={}/{credential_scope}, SignedHeaders={}, Signature={signature}",
+        credentials.access_key_id,
+        signed_headers(headers)
     )
 }
 
@@ -602,6 +622,7 @@ impl BedrockProvider {
 
         let authorization = build_authorization_header(
             credentials,
+            SIGNING_SERVICE,
             "POST",
             &canonical_uri,
             "",
@@ -835,6 +856,71 @@ mod tests {
         );
     }
 
+    // AWS SigV4 test suite "get-vanilla" case: GET / on example.amazonaws.com.
+    fn get_vanilla_headers() -> Vec<(String, String)> {
+        vec![
+            ("host".to_string(), "example.amazonaws.com".to_string()),
+            ("x-amz-date".to_string(), "20150830T123600Z".to_string()),
+        ]
+    }
+
+    #[test]
+    fn canonical_request_matches_aws_get_vanilla_vector() {
+        let request = canonical_request("GET", "/", "", &get_vanilla_headers(), b"");
+        assert_eq!(
+            request,
+            "GET\n/\n\nhost:example.amazonaws.com\nx-amz-date:20150830T123600Z\n\n\
+             host;x-amz-date\n\
+             e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn string_to_sign_matches_aws_get_vanilla_vector() {
+        let request = canonical_request("GET", "/", "", &get_vanilla_headers(), b"");
+        let sts = string_to_sign(
+            "20150830T123600Z",
+            "20150830/us-east-1/service/aws4_request",
+            &request,
+        );
+        assert_eq!(
+            sts,
+            "AWS4-HMAC-SHA256\n20150830T123600Z\n20150830/us-east-1/service/aws4_request\n\
+             bb579772317eb040ac9ed261061d46c1f17a8133879d6129b6e1c25292927e63"
+        );
+    }
+
+    #[test]
+    fn build_authorization_header_matches_aws_get_vanilla_vector() {
+        let credentials = AwsCredentials {
+            access_key_id: "AKIDEXAMPLE".to_string(),
+            secret_access_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string(),
+            session_token: None,
+            region: "us-east-1".to_string(),
+        };
+        let timestamp = chrono::DateTime::parse_from_rfc3339("2015-08-30T12:36:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+
+        let auth = build_authorization_header(
+            &credentials,
+            "service",
+            "GET",
+            "/",
+            "",
+            &get_vanilla_headers(),
+            b"",
+            &timestamp,
+        );
+
+        assert_eq!(
+            auth,
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+        );
+    }
+
     #[test]
     fn build_authorization_header_format() {
         let credentials = AwsCredentials {
@@ -859,6 +945,7 @@ mod tests {
 
         let auth = build_authorization_header(
             &credentials,
+            SIGNING_SERVICE,
             "POST",
             "/model/anthropic.claude-3-sonnet/converse",
             "",
@@ -902,6 +989,7 @@ mod tests {
 
         let auth = build_authorization_header(
             &credentials,
+            SIGNING_SERVICE,
             "POST",
             "/model/test-model/converse",
             "",